@@ -0,0 +1,109 @@
+// src-tauri/src/apps/linux.rs
+//
+// Enumerates freedesktop `.desktop` entries from the XDG application
+// directories: `$XDG_DATA_DIRS/applications` for system-wide entries and
+// `~/.local/share/applications` for per-user ones. Entries marked
+// `NoDisplay=true` or `Hidden=true` are skipped, matching how desktop
+// environments themselves decide what belongs in an app grid/menu.
+
+use super::{guess_category, AppData, AppSource};
+use crate::desktop_entry;
+use crate::packaging;
+use std::fs;
+use std::path::PathBuf;
+
+pub struct LinuxSource;
+
+impl AppSource for LinuxSource {
+    fn scan(&self) -> Vec<AppData> {
+        let mut apps = Vec::new();
+        let mut id_counter = 0;
+
+        for dir in application_dirs() {
+            let Ok(entries) = fs::read_dir(&dir) else {
+                continue;
+            };
+
+            for entry in entries.flatten() {
+                let path = entry.path();
+                if path.extension().and_then(|s| s.to_str()) != Some("desktop") {
+                    continue;
+                }
+
+                let Ok(contents) = fs::read_to_string(&path) else {
+                    continue;
+                };
+
+                let Some(entry_fields) = desktop_entry::parse(&contents) else {
+                    continue;
+                };
+
+                if entry_fields.no_display || entry_fields.hidden {
+                    continue;
+                }
+
+                let Some(name) = entry_fields.name else {
+                    continue;
+                };
+
+                let category = entry_fields
+                    .categories
+                    .as_deref()
+                    .and_then(category_from_freedesktop)
+                    .unwrap_or_else(|| guess_category(&name));
+                let packaging = packaging::detect(&entry_fields);
+
+                apps.push(AppData {
+                    id: id_counter.to_string(),
+                    name,
+                    path: path.to_string_lossy().to_string(),
+                    category,
+                    packaging,
+                });
+                id_counter += 1;
+            }
+        }
+
+        apps
+    }
+}
+
+/// The `$XDG_DATA_DIRS/applications` directories plus the per-user
+/// `~/.local/share/applications` directory, in the order they should be
+/// scanned. Falls back to the freedesktop-specified defaults when the
+/// relevant environment variables aren't set.
+fn application_dirs() -> Vec<PathBuf> {
+    let mut dirs = Vec::new();
+
+    if let Some(home) = dirs::home_dir() {
+        dirs.push(home.join(".local/share/applications"));
+    }
+
+    let xdg_data_dirs = std::env::var("XDG_DATA_DIRS")
+        .unwrap_or_else(|_| "/usr/local/share:/usr/share".to_string());
+    for dir in xdg_data_dirs.split(':').filter(|s| !s.is_empty()) {
+        dirs.push(PathBuf::from(dir).join("applications"));
+    }
+
+    dirs
+}
+
+/// Collapses the freedesktop main categories into our own taxonomy. Returns
+/// `None` if nothing recognizable is present, so the caller can fall back to
+/// name-based guessing.
+fn category_from_freedesktop(categories: &str) -> Option<String> {
+    for category in categories.split(';') {
+        let bucket = match category {
+            "Development" => "Development",
+            "Network" | "WebBrowser" | "Email" | "InstantMessaging" | "Chat" => "Social",
+            "AudioVideo" | "Audio" | "Video" => "Media",
+            "Game" => "Media",
+            "Graphics" => "Design",
+            "System" | "Settings" => "System",
+            "Utility" => "System",
+            _ => continue,
+        };
+        return Some(bucket.to_string());
+    }
+    None
+}
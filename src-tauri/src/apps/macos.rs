@@ -0,0 +1,95 @@
+// src-tauri/src/apps/macos.rs
+//
+// Enumerates `.app` bundles under the standard macOS application
+// directories. This is a straight port of the original (pre-platform-split)
+// scanning logic.
+
+use super::{guess_category, AppData, AppSource};
+use crate::packaging::Packaging;
+use std::fs;
+use std::path::Path;
+
+const SCAN_DIRS: &[&str] = &[
+    "/Applications",
+    "/System/Applications",
+    "/System/Applications/Utilities",
+];
+
+pub struct MacOsSource;
+
+impl AppSource for MacOsSource {
+    fn scan(&self) -> Vec<AppData> {
+        let mut apps = Vec::new();
+        let mut id_counter = 0;
+
+        for folder_path in SCAN_DIRS {
+            let path = Path::new(folder_path);
+
+            if let Ok(entries) = fs::read_dir(path) {
+                for entry in entries.flatten() {
+                    let path_buf = entry.path();
+                    let path_str = path_buf.to_string_lossy().to_string();
+
+                    if path_buf.extension().and_then(|s| s.to_str()) != Some("app") {
+                        continue;
+                    }
+
+                    let Some(stem) = path_buf.file_stem().and_then(|s| s.to_str()) else {
+                        continue;
+                    };
+                    if stem.starts_with('.') {
+                        continue;
+                    }
+
+                    let category = category_from_bundle(&path_buf).unwrap_or_else(|| guess_category(stem));
+
+                    apps.push(AppData {
+                        id: id_counter.to_string(),
+                        name: stem.to_string(),
+                        path: path_str,
+                        category,
+                        packaging: Packaging::Native,
+                    });
+                    id_counter += 1;
+                }
+            }
+        }
+
+        apps
+    }
+}
+
+/// Reads `LSApplicationCategoryType` out of the bundle's `Info.plist` and
+/// maps Apple's UTI category string onto our own taxonomy. Returns `None`
+/// when the plist is missing, the key isn't set, or the category doesn't
+/// map to anything we track -- callers should fall back to `guess_category`
+/// in that case.
+fn category_from_bundle(bundle_path: &Path) -> Option<String> {
+    let plist_path = bundle_path.join("Contents/Info.plist");
+    let file = fs::File::open(plist_path).ok()?;
+    let value: serde_json::Value = plist::from_reader(file).ok()?;
+    let uti = value.get("LSApplicationCategoryType")?.as_str()?;
+    category_from_uti(uti)
+}
+
+/// Collapses Apple's `public.app-category.*` UTIs into our taxonomy. See
+/// https://developer.apple.com/documentation/bundleresources/information_property_list/lsapplicationcategorytype
+/// for the full list -- only the ones with an obvious bucket are mapped.
+fn category_from_uti(uti: &str) -> Option<String> {
+    let bucket = match uti {
+        "public.app-category.developer-tools" => "Development",
+        "public.app-category.social-networking" | "public.app-category.news" => "Social",
+        "public.app-category.music"
+        | "public.app-category.video"
+        | "public.app-category.photography"
+        | "public.app-category.entertainment"
+        | "public.app-category.sports"
+        | "public.app-category.games" => "Media",
+        "public.app-category.business"
+        | "public.app-category.productivity"
+        | "public.app-category.utilities" => "System",
+        "public.app-category.graphics-design" => "Design",
+        _ => return None,
+    };
+    Some(bucket.to_string())
+}
@@ -0,0 +1,82 @@
+// src-tauri/src/apps/mod.rs
+//
+// Platform abstraction for app enumeration. Each OS gets its own `AppSource`
+// implementation behind a `#[cfg]` gate; `platform_source` picks the right
+// one at compile time so the rest of the app never has to care which
+// platform it's running on.
+
+#[cfg(target_os = "macos")]
+mod macos;
+#[cfg(target_os = "linux")]
+mod linux;
+#[cfg(target_os = "windows")]
+mod windows;
+
+use crate::config::{get_config_path, load_overrides};
+use crate::packaging::Packaging;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct AppData {
+    pub id: String,
+    pub name: String,
+    pub path: String,
+    pub category: String,
+    pub packaging: Packaging,
+}
+
+/// Scans one platform's installed-apps locations and returns what it found.
+/// Implementors should not apply category overrides or sort the result --
+/// that's handled once, centrally, by `get_installed_apps`.
+pub trait AppSource {
+    fn scan(&self) -> Vec<AppData>;
+}
+
+#[cfg(target_os = "macos")]
+fn platform_source() -> impl AppSource {
+    macos::MacOsSource
+}
+
+#[cfg(target_os = "linux")]
+fn platform_source() -> impl AppSource {
+    linux::LinuxSource
+}
+
+#[cfg(target_os = "windows")]
+fn platform_source() -> impl AppSource {
+    windows::WindowsSource
+}
+
+/// Hand-matches a few English substrings against the app name. This is only
+/// a last-resort fallback for platforms/entries that carry no richer
+/// metadata -- prefer deriving the category from the OS's own metadata when
+/// it's available.
+pub fn guess_category(name: &str) -> String {
+    let lower = name.to_lowercase();
+    if lower.contains("code") || lower.contains("term") || lower.contains("xcode") { return "Development".to_string(); }
+    if lower.contains("discord") || lower.contains("slack") || lower.contains("mail") || lower.contains("message") { return "Social".to_string(); }
+    if lower.contains("spotify") || lower.contains("music") || lower.contains("tv") || lower.contains("photo") { return "Media".to_string(); }
+    if lower.contains("chrome") || lower.contains("safari") || lower.contains("firefox") { return "Internet".to_string(); }
+    if lower.contains("figma") || lower.contains("adobe") { return "Design".to_string(); }
+    if lower.contains("settings") || lower.contains("preference") || lower.contains("activity") { return "System".to_string(); }
+    "Other".to_string()
+}
+
+#[tauri::command]
+pub fn get_installed_apps() -> Vec<AppData> {
+    let config_path = get_config_path();
+    let overrides = load_overrides(&config_path);
+
+    let mut apps = platform_source().scan();
+
+    for app in &mut apps {
+        if let Some(over) = overrides.get(&app.path) {
+            if let Some(c) = &over.category {
+                app.category = c.clone();
+            }
+        }
+    }
+
+    apps.sort_by(|a, b| a.name.to_lowercase().cmp(&b.name.to_lowercase()));
+    apps
+}
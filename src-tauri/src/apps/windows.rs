@@ -0,0 +1,71 @@
+// src-tauri/src/apps/windows.rs
+//
+// Walks the Start Menu `.lnk` shortcuts in the common and per-user Programs
+// folders. Windows has no equivalent of a queryable "installed apps"
+// registry that covers every app (only MSI/MSIX-installed ones show up in
+// the uninstall registry keys), so the Start Menu shortcuts are the most
+// reliable cross-vendor source.
+
+use super::{guess_category, AppData, AppSource};
+use crate::packaging::Packaging;
+use std::path::PathBuf;
+
+pub struct WindowsSource;
+
+impl AppSource for WindowsSource {
+    fn scan(&self) -> Vec<AppData> {
+        let mut apps = Vec::new();
+        let mut id_counter = 0;
+
+        for dir in start_menu_dirs() {
+            for entry in walk_lnk_files(&dir) {
+                let Some(stem) = entry.file_stem().and_then(|s| s.to_str()) else {
+                    continue;
+                };
+
+                apps.push(AppData {
+                    id: id_counter.to_string(),
+                    name: stem.to_string(),
+                    path: entry.to_string_lossy().to_string(),
+                    category: guess_category(stem),
+                    packaging: Packaging::Native,
+                });
+                id_counter += 1;
+            }
+        }
+
+        apps
+    }
+}
+
+/// The common (all-users) and per-user Start Menu "Programs" folders.
+fn start_menu_dirs() -> Vec<PathBuf> {
+    let mut dirs = Vec::new();
+
+    if let Ok(program_data) = std::env::var("ProgramData") {
+        dirs.push(PathBuf::from(program_data).join("Microsoft/Windows/Start Menu/Programs"));
+    }
+    if let Some(data_dir) = dirs::data_dir() {
+        dirs.push(data_dir.join("Microsoft/Windows/Start Menu/Programs"));
+    }
+
+    dirs
+}
+
+fn walk_lnk_files(dir: &PathBuf) -> Vec<PathBuf> {
+    let mut shortcuts = Vec::new();
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return shortcuts;
+    };
+
+    for entry in entries.flatten() {
+        let path = entry.path();
+        if path.is_dir() {
+            shortcuts.extend(walk_lnk_files(&path));
+        } else if path.extension().and_then(|s| s.to_str()).map(|s| s.eq_ignore_ascii_case("lnk")) == Some(true) {
+            shortcuts.push(path);
+        }
+    }
+
+    shortcuts
+}
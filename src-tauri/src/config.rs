@@ -0,0 +1,43 @@
+// src-tauri/src/config.rs
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct AppOverride {
+    pub category: Option<String>,
+}
+
+pub fn get_config_path() -> PathBuf {
+    let mut path = dirs::home_dir().expect("Could not find home directory");
+    path.push(".app-launcher-config.json");
+    path
+}
+
+pub fn load_overrides(path: &Path) -> HashMap<String, AppOverride> {
+    if let Ok(data) = fs::read_to_string(path) {
+        if let Ok(map) = serde_json::from_str(&data) {
+            return map;
+        }
+    }
+    HashMap::new()
+}
+
+#[tauri::command]
+pub fn save_app_config(path: String, category: String) {
+    let config_path = get_config_path();
+    let mut overrides = load_overrides(&config_path);
+
+    overrides.insert(
+        path,
+        AppOverride {
+            category: Some(category),
+        },
+    );
+
+    if let Ok(json) = serde_json::to_string_pretty(&overrides) {
+        let _ = fs::write(config_path, json);
+    }
+}
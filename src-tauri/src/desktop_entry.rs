@@ -0,0 +1,137 @@
+// src-tauri/src/desktop_entry.rs
+//
+// Shared parsing for freedesktop `.desktop` entries. Used both when
+// enumerating installed apps (`apps::linux`) and when launching one
+// (`launch::linux`), so the two stay in sync on what counts as the `Exec`
+// line and how its field codes expand.
+
+pub struct DesktopEntry {
+    pub name: Option<String>,
+    pub exec: Option<String>,
+    pub icon: Option<String>,
+    pub categories: Option<String>,
+    pub no_display: bool,
+    pub hidden: bool,
+    /// The `X-Flatpak` key Flatpak's desktop-file exporter stamps onto
+    /// generated entries, naming the app's Flatpak application ID.
+    pub x_flatpak: Option<String>,
+}
+
+/// Parses the `[Desktop Entry]` group of a `.desktop` file. Only the group
+/// we care about is read; later groups (e.g. `[Desktop Action ...]`) are
+/// ignored.
+pub fn parse(contents: &str) -> Option<DesktopEntry> {
+    let mut entry = DesktopEntry {
+        name: None,
+        exec: None,
+        icon: None,
+        categories: None,
+        no_display: false,
+        hidden: false,
+        x_flatpak: None,
+    };
+    let mut in_desktop_entry_group = false;
+    let mut seen_desktop_entry_group = false;
+
+    for line in contents.lines() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        if line.starts_with('[') && line.ends_with(']') {
+            in_desktop_entry_group = line == "[Desktop Entry]";
+            if in_desktop_entry_group {
+                seen_desktop_entry_group = true;
+            } else if seen_desktop_entry_group {
+                // We've reached a later group after having read the one we wanted.
+                break;
+            }
+            continue;
+        }
+
+        if !in_desktop_entry_group {
+            continue;
+        }
+
+        let Some((key, value)) = line.split_once('=') else {
+            continue;
+        };
+        let key = key.trim();
+        let value = value.trim();
+
+        match key {
+            "Name" => entry.name = Some(value.to_string()),
+            "Exec" => entry.exec = Some(value.to_string()),
+            "Icon" => entry.icon = Some(value.to_string()),
+            "Categories" => entry.categories = Some(value.to_string()),
+            "NoDisplay" => entry.no_display = value.eq_ignore_ascii_case("true"),
+            "Hidden" => entry.hidden = value.eq_ignore_ascii_case("true"),
+            "X-Flatpak" => entry.x_flatpak = Some(value.to_string()),
+            _ => {}
+        }
+    }
+
+    seen_desktop_entry_group.then_some(entry)
+}
+
+/// Tokenizes an `Exec` value into argv, expanding the field codes defined by
+/// the Desktop Entry Specification:
+///
+/// - `%f` / `%F`: a single file path / the full list of file paths
+/// - `%u` / `%U`: a single URL / the full list of URLs
+/// - `%i`, `%c`, `%k`: icon, translated name, path to the `.desktop` file --
+///   we don't have values worth substituting for these, so they're dropped
+/// - `%%`: a literal `%`
+///
+/// `targets` is used for both the file and URL codes since we don't
+/// distinguish the two at the call site; codes are stripped entirely when
+/// no targets were given, per spec ("if no files are passed, the program is
+/// executed without any file arguments").
+pub fn expand_exec_field_codes(exec: &str, targets: &[String]) -> Vec<String> {
+    let mut argv = Vec::new();
+
+    for token in tokenize(exec) {
+        match token.as_str() {
+            "%f" | "%u" => {
+                if let Some(first) = targets.first() {
+                    argv.push(first.clone());
+                }
+            }
+            "%F" | "%U" => argv.extend(targets.iter().cloned()),
+            "%i" | "%c" | "%k" => {}
+            "%%" => argv.push("%".to_string()),
+            other => argv.push(other.replace("%%", "%")),
+        }
+    }
+
+    argv
+}
+
+/// Splits an `Exec` value on whitespace, honoring single/double quoted
+/// segments so paths containing spaces survive intact.
+fn tokenize(exec: &str) -> Vec<String> {
+    let mut tokens = Vec::new();
+    let mut current = String::new();
+    let mut quote: Option<char> = None;
+    let mut chars = exec.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match quote {
+            Some(q) if c == q => quote = None,
+            Some(_) => current.push(c),
+            None if c == '"' || c == '\'' => quote = Some(c),
+            None if c.is_whitespace() => {
+                if !current.is_empty() {
+                    tokens.push(std::mem::take(&mut current));
+                }
+            }
+            None => current.push(c),
+        }
+    }
+    if !current.is_empty() {
+        tokens.push(current);
+    }
+
+    tokens
+}
@@ -0,0 +1,67 @@
+// src-tauri/src/icon.rs
+
+use crate::icon_cache;
+use base64::{engine::general_purpose, Engine as _};
+use std::fs;
+use std::path::Path;
+
+#[tauri::command]
+pub fn get_app_icon(app_path: String) -> Option<String> {
+    let path = Path::new(&app_path);
+    let mtime = fs::metadata(path).and_then(|m| m.modified()).ok();
+
+    if let Some(mtime) = mtime {
+        if let Some(cached) = icon_cache::get(&app_path, mtime) {
+            return Some(to_data_url(&cached));
+        }
+    }
+
+    let png_buffer = extract_icon_png(path)?;
+
+    if let Some(mtime) = mtime {
+        icon_cache::put(&app_path, mtime, &png_buffer);
+    }
+
+    Some(to_data_url(&png_buffer))
+}
+
+fn to_data_url(png_bytes: &[u8]) -> String {
+    let base64_str = general_purpose::STANDARD.encode(png_bytes);
+    format!("data:image/png;base64,{}", base64_str)
+}
+
+/// Decodes the bundle's `.icns` into a PNG, uncached.
+fn extract_icon_png(path: &Path) -> Option<Vec<u8>> {
+    // 1. Find Info.plist
+    let plist_path = path.join("Contents/Info.plist");
+
+    // 2. Read Plist to find icon filename
+    let icon_name = if let Ok(file) = fs::File::open(&plist_path) {
+        let value: serde_json::Value = plist::from_reader(file).ok()?;
+        value.get("CFBundleIconFile")?.as_str()?.to_string()
+    } else {
+        return None;
+    };
+
+    // 3. Construct path to .icns
+    let mut icon_path = path.join("Contents/Resources").join(&icon_name);
+    if icon_path.extension().is_none() {
+        icon_path.set_extension("icns");
+    }
+
+    // 4. Read .icns file
+    let file = fs::File::open(&icon_path).ok()?;
+    let icon_family = icns::IconFamily::read(file).ok()?;
+
+    // 5. Extract icon
+    let image = icon_family.get_icon_with_type(icns::IconType::RGBA32_128x128_2x)
+        .or_else(|_| icon_family.get_icon_with_type(icns::IconType::RGBA32_128x128))
+        .or_else(|_| icon_family.get_icon_with_type(icns::IconType::RGBA32_32x32_2x))
+        .ok()?;
+
+    // 6. Convert to PNG
+    let mut png_buffer = Vec::new();
+    image.write_png(&mut png_buffer).ok()?;
+
+    Some(png_buffer)
+}
@@ -0,0 +1,53 @@
+// src-tauri/src/icon_cache.rs
+//
+// Extracting an icon (opening `Info.plist`, decoding the `.icns`,
+// re-encoding a PNG) is expensive enough that doing it on every single
+// `get_app_icon` call makes rendering a full grid sluggish. This caches the
+// extracted PNG bytes on disk, keyed by the app's path and its bundle's
+// modification time, so a refresh only re-extracts icons for apps that
+// actually changed.
+
+use std::collections::hash_map::DefaultHasher;
+use std::fs;
+use std::hash::{Hash, Hasher};
+use std::path::PathBuf;
+use std::time::SystemTime;
+
+fn get_cache_dir() -> PathBuf {
+    let mut path = dirs::home_dir().expect("Could not find home directory");
+    path.push(".app-launcher-icon-cache");
+    path
+}
+
+/// One cache entry per (app path, mtime) pair -- a bundle update changes the
+/// mtime and so gets a fresh entry, naturally invalidating the stale one.
+fn cache_file_path(app_path: &str, mtime: SystemTime) -> PathBuf {
+    let mut hasher = DefaultHasher::new();
+    app_path.hash(&mut hasher);
+    mtime.hash(&mut hasher);
+    let key = hasher.finish();
+
+    get_cache_dir().join(format!("{:016x}.png", key))
+}
+
+/// Returns the cached PNG bytes for `app_path` if its bundle's mtime matches
+/// what was cached, or `None` on a cache miss.
+pub fn get(app_path: &str, mtime: SystemTime) -> Option<Vec<u8>> {
+    fs::read(cache_file_path(app_path, mtime)).ok()
+}
+
+/// Persists `png_bytes` as the cached icon for `app_path` at `mtime`.
+pub fn put(app_path: &str, mtime: SystemTime, png_bytes: &[u8]) {
+    let cache_dir = get_cache_dir();
+    if fs::create_dir_all(&cache_dir).is_err() {
+        return;
+    }
+    let _ = fs::write(cache_file_path(app_path, mtime), png_bytes);
+}
+
+/// Deletes the entire on-disk icon cache. Missing entries are re-extracted
+/// and re-cached the next time `get_app_icon` is called for them.
+#[tauri::command]
+pub fn clear_icon_cache() {
+    let _ = fs::remove_dir_all(get_cache_dir());
+}
@@ -0,0 +1,70 @@
+// src-tauri/src/launch/linux.rs
+//
+// `app_path` here is the `.desktop` file's own path (that's what
+// `apps::linux` records as `AppData::path`), so launching means re-reading
+// its `Exec` line, classifying how the app is packaged, and dispatching
+// accordingly -- a Flatpak's `Exec` line shouldn't be exec'd naively, it
+// needs to go through `flatpak run`.
+
+use crate::desktop_entry::{self, DesktopEntry};
+use crate::packaging::{self, Packaging};
+use std::path::Path;
+use std::process::Command;
+
+pub fn build_command(app_path: &str, targets: &[String]) -> Command {
+    let entry = std::fs::read_to_string(app_path)
+        .ok()
+        .and_then(|contents| desktop_entry::parse(&contents));
+
+    let Some(entry) = entry else {
+        // No readable Exec line -- fail in a way that's at least visible:
+        // attempt to execute the desktop file directly rather than silently
+        // doing nothing.
+        return Command::new(app_path);
+    };
+
+    match packaging::detect(&entry) {
+        Packaging::Flatpak => build_flatpak_command(&entry, app_path, targets),
+        Packaging::Snap | Packaging::AppImage | Packaging::Native => {
+            build_exec_command(&entry, app_path, targets)
+        }
+    }
+}
+
+/// Runs the Flatpak app through `flatpak run <app-id>` rather than trusting
+/// whatever wrapper invocation happens to be in `Exec` -- that's the
+/// supported way to start a Flatpak app outside of its desktop-file launch.
+fn build_flatpak_command(entry: &DesktopEntry, app_path: &str, targets: &[String]) -> Command {
+    let app_id = entry.x_flatpak.clone().unwrap_or_else(|| {
+        // Flatpak's exporter names the desktop file after the app ID, so
+        // the file stem is a reliable fallback when `X-Flatpak` is absent.
+        Path::new(app_path)
+            .file_stem()
+            .map(|s| s.to_string_lossy().to_string())
+            .unwrap_or_default()
+    });
+
+    let mut cmd = Command::new("flatpak");
+    cmd.arg("run").arg(app_id).args(targets);
+    cmd
+}
+
+/// Runs whatever `Exec` points to directly, expanding its field codes. This
+/// covers plain native apps, Snap apps (whose `Exec` already points at the
+/// snap's own wrapper under `/snap/bin/`), and AppImages (whose `Exec`
+/// already points at the image itself).
+fn build_exec_command(entry: &DesktopEntry, app_path: &str, targets: &[String]) -> Command {
+    let Some(exec) = &entry.exec else {
+        return Command::new(app_path);
+    };
+
+    let argv = desktop_entry::expand_exec_field_codes(exec, targets);
+    let mut iter = argv.into_iter();
+    let Some(program) = iter.next() else {
+        return Command::new(app_path);
+    };
+
+    let mut cmd = Command::new(program);
+    cmd.args(iter);
+    cmd
+}
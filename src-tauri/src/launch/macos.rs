@@ -0,0 +1,15 @@
+// src-tauri/src/launch/macos.rs
+
+use std::process::Command;
+
+/// `open <app>` to start it bare, or `open -a <app> <targets...>` to open
+/// specific files/URLs with it.
+pub fn build_command(app_path: &str, targets: &[String]) -> Command {
+    let mut cmd = Command::new("open");
+    if targets.is_empty() {
+        cmd.arg(app_path);
+    } else {
+        cmd.arg("-a").arg(app_path).args(targets);
+    }
+    cmd
+}
@@ -0,0 +1,150 @@
+// src-tauri/src/launch/mod.rs
+//
+// Spawns apps with a sanitized environment. Launchers packaged as an
+// AppImage/Flatpak/etc. run with PATH-style environment variables
+// (`PATH`, `LD_LIBRARY_PATH`, `GST_PLUGIN_SYSTEM_PATH`, `GTK_PATH`, ...)
+// pointing into their own bundle so they can find their bundled libraries.
+// If that environment is handed down unchanged to a launched app, the app
+// picks up the launcher's libraries instead of its own and can crash or
+// misbehave in ways that are miserable to debug. Every `Command` built here
+// goes through `normalize_pathlist` first to strip that pollution out.
+//
+// Building the actual `Command` for a given app is platform-specific (macOS
+// shells out to `open`, Linux expands a `.desktop` entry's `Exec` line,
+// Windows hands the Start Menu shortcut to the shell via `cmd /c start`), so
+// that part is split out
+// per-OS the same way `apps` is.
+
+#[cfg(target_os = "macos")]
+mod macos;
+#[cfg(target_os = "linux")]
+mod linux;
+#[cfg(target_os = "windows")]
+mod windows;
+
+use std::env;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+/// `:`-separated environment variables that carry search paths and should be
+/// normalized before a child process inherits them.
+const PATHLIST_VARS: &[&str] = &[
+    "PATH",
+    "XDG_DATA_DIRS",
+    "LD_LIBRARY_PATH",
+    "GST_PLUGIN_SYSTEM_PATH",
+    "GTK_PATH",
+];
+
+/// Splits a `:`-separated PATH-style value, drops empty segments and any
+/// segment that resolves inside `launcher_dir`, and de-duplicates -- keeping
+/// the *later* (lower-priority) occurrence of each path, since that's the
+/// one a normal, unpolluted environment would have put there. Returns `None`
+/// when nothing is left, so the caller can remove the variable entirely
+/// instead of setting it to an empty string.
+fn normalize_pathlist(value: &str, launcher_dir: &Path) -> Option<String> {
+    let segments: Vec<&str> = value.split(':').filter(|s| !s.is_empty()).collect();
+
+    let mut kept = Vec::with_capacity(segments.len());
+    for (i, segment) in segments.iter().enumerate() {
+        if resolves_inside(segment, launcher_dir) {
+            continue;
+        }
+        // Keep this occurrence only if no *later* segment duplicates it.
+        if segments[i + 1..].contains(segment) {
+            continue;
+        }
+        kept.push(*segment);
+    }
+
+    if kept.is_empty() {
+        None
+    } else {
+        Some(kept.join(":"))
+    }
+}
+
+fn resolves_inside(segment: &str, launcher_dir: &Path) -> bool {
+    let segment_path = Path::new(segment);
+    let resolved = segment_path.canonicalize().unwrap_or_else(|_| segment_path.to_path_buf());
+    resolved.starts_with(launcher_dir)
+}
+
+/// The directory the launcher itself is installed/mounted under -- the
+/// running AppImage's mount point (`$APPDIR`), `/app` inside a Flatpak
+/// sandbox, or the root of the `.app` bundle; otherwise the directory
+/// holding the launcher's executable. Segments of a PATH-style variable that
+/// resolve inside here are the launcher's own bundled libraries, not the
+/// system's. `None` when the launcher's own location can't be determined.
+fn launcher_dir() -> Option<PathBuf> {
+    if let Some(appdir) = env::var_os("APPDIR") {
+        return Some(PathBuf::from(appdir));
+    }
+    if env::var_os("FLATPAK_ID").is_some() {
+        return Some(PathBuf::from("/app"));
+    }
+    let exe = env::current_exe().ok()?;
+    let bundle = exe.ancestors().find(|dir| dir.extension().is_some_and(|ext| ext == "app"));
+    bundle.or_else(|| exe.parent()).map(Path::to_path_buf)
+}
+
+/// Applies environment normalization to `cmd` in place: every PATH-style
+/// variable in `PATHLIST_VARS` is rewritten via `normalize_pathlist`, and
+/// removed entirely if nothing survives. The environment is left unchanged
+/// when `launcher_dir` can't determine where the launcher lives.
+pub fn sanitize_environment(cmd: &mut Command) {
+    let Some(launcher_dir) = launcher_dir() else {
+        return;
+    };
+
+    for var in PATHLIST_VARS {
+        let Ok(value) = env::var(var) else {
+            continue;
+        };
+
+        match normalize_pathlist(&value, &launcher_dir) {
+            Some(normalized) => {
+                cmd.env(var, normalized);
+            }
+            None => {
+                cmd.env_remove(var);
+            }
+        }
+    }
+}
+
+/// Builds the `Command` that launches `app_path`, substituting `targets`
+/// (files/URLs) into it where the platform supports that. `targets` is
+/// empty for a bare "just start the app" launch.
+#[cfg(target_os = "macos")]
+fn build_command(app_path: &str, targets: &[String]) -> Command {
+    macos::build_command(app_path, targets)
+}
+
+#[cfg(target_os = "linux")]
+fn build_command(app_path: &str, targets: &[String]) -> Command {
+    linux::build_command(app_path, targets)
+}
+
+#[cfg(target_os = "windows")]
+fn build_command(app_path: &str, targets: &[String]) -> Command {
+    windows::build_command(app_path, targets)
+}
+
+fn spawn(app_path: &str, targets: &[String]) {
+    let mut cmd = build_command(app_path, targets);
+    sanitize_environment(&mut cmd);
+    let _ = cmd.spawn();
+}
+
+#[tauri::command]
+pub fn launch_app(path: String) {
+    spawn(&path, &[]);
+}
+
+/// Opens one or more files/URLs with a specific application, instead of
+/// just starting it bare.
+#[tauri::command]
+pub fn launch_app_with(app_path: String, targets: Vec<String>) {
+    spawn(&app_path, &targets);
+}
@@ -0,0 +1,14 @@
+// src-tauri/src/launch/windows.rs
+
+use std::process::Command;
+
+/// `apps::windows` records the `.lnk` shortcut's own path, and `.lnk` files
+/// aren't directly executable via `CreateProcess` -- they need the shell to
+/// resolve the link target. `cmd /c start` does that resolution for us and
+/// forwards any extra arguments (file/URL targets) to the resolved target.
+pub fn build_command(app_path: &str, targets: &[String]) -> Command {
+    let mut cmd = Command::new("cmd");
+    cmd.args(["/c", "start", "", app_path]);
+    cmd.args(targets);
+    cmd
+}
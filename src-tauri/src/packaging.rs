@@ -0,0 +1,57 @@
+// src-tauri/src/packaging.rs
+//
+// Detects how a Linux app is packaged. A `.desktop` entry's `Exec` line
+// can't be exec'd naively for every packaging format: Flatpak apps are
+// meant to be started through `flatpak run`, not by invoking whatever path
+// happens to be in `Exec`. Recording this on `AppData` lets `launch::linux`
+// dispatch each app through the right mechanism instead of guessing.
+
+use crate::desktop_entry::DesktopEntry;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum Packaging {
+    #[default]
+    Native,
+    Flatpak,
+    Snap,
+    AppImage,
+}
+
+pub fn is_flatpak(entry: &DesktopEntry) -> bool {
+    entry.x_flatpak.is_some()
+        || entry.exec.as_deref().is_some_and(|exec| {
+            exec.trim_start().starts_with("flatpak run") || exec.contains("/flatpak/exports/")
+        })
+}
+
+pub fn is_snap(entry: &DesktopEntry) -> bool {
+    entry
+        .exec
+        .as_deref()
+        .is_some_and(|exec| exec.contains("/snap/bin/") || exec.contains("/snap/"))
+}
+
+pub fn is_appimage(entry: &DesktopEntry) -> bool {
+    entry
+        .exec
+        .as_deref()
+        .is_some_and(|exec| exec.to_lowercase().contains(".appimage"))
+}
+
+/// Classifies a parsed `.desktop` entry's packaging format. The checks run
+/// in this order and the first match wins, so an entry carrying an
+/// `X-Flatpak` key counts as Flatpak whatever its `Exec` says; AppImage is
+/// checked last since it's just a file-extension heuristic.
+pub fn detect(entry: &DesktopEntry) -> Packaging {
+    if is_flatpak(entry) {
+        Packaging::Flatpak
+    } else if is_snap(entry) {
+        Packaging::Snap
+    } else if is_appimage(entry) {
+        Packaging::AppImage
+    } else {
+        Packaging::Native
+    }
+}